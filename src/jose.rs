@@ -0,0 +1,243 @@
+// Copyright 2023 Antonio Estevez <aestevez@opencanarias.es>
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express
+// or implied. See the License for the specific language governing
+// permissions and limitations under the License.
+
+//! # JOSE (JSON Object Signing and Encryption)
+//!
+//! Containers for JSON Web Signature / JSON Web Token values, as defined by
+//! [RFC 7515](https://tools.ietf.org/html/rfc7515) and
+//! [RFC 7519](https://tools.ietf.org/html/rfc7519). This module only parses and
+//! renders the envelope: it never signs or verifies, so this crate stays crypto-free.
+//!
+
+#![deny(missing_docs)]
+
+use crate::json::OneOrMany;
+use crate::Error;
+
+use base64::{engine::general_purpose, Engine as _};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use std::collections::HashMap;
+
+/// The protected header of a JWS/JWT, as defined by
+/// [RFC 7515 §4](https://tools.ietf.org/html/rfc7515#section-4).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Header {
+    /// The signing algorithm, e.g. `"RS256"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alg: Option<String>,
+    /// The identifier of the key used to sign.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kid: Option<String>,
+    /// The media type of the whole JWS/JWT.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub typ: Option<String>,
+    /// The media type of the payload.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cty: Option<String>,
+    /// The extensions that a consumer must understand to accept the JWS/JWT.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub crit: Option<OneOrMany<String>>,
+    /// Any additional header parameters not covered by the named fields above.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+impl Header {
+    /// Creates a new, empty `Header`.
+    pub fn new() -> Self {
+        Self {
+            alg: None,
+            kid: None,
+            typ: None,
+            cty: None,
+            crit: None,
+            extra: HashMap::new(),
+        }
+    }
+}
+
+impl Default for Header {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn encode_segment<T: Serialize>(value: &T) -> Result<String, Error> {
+    let bytes = serde_json::to_vec(value).map_err(|_| Error::InvalidJws)?;
+    Ok(general_purpose::URL_SAFE_NO_PAD.encode(bytes))
+}
+
+fn decode_segment(segment: &str) -> Result<Vec<u8>, Error> {
+    general_purpose::URL_SAFE_NO_PAD
+        .decode(segment)
+        .map_err(|_| Error::InvalidJws)
+}
+
+/// A JWS/JWT in the three-segment compact serialization, as defined by
+/// [RFC 7515 §7.1](https://tools.ietf.org/html/rfc7515#section-7.1).
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompactJws {
+    /// The protected header.
+    pub header: Header,
+    /// The payload bytes.
+    pub payload: Vec<u8>,
+    /// The signature bytes.
+    pub signature: Vec<u8>,
+}
+
+impl CompactJws {
+    /// Creates a new `CompactJws` from its parts.
+    pub fn new(header: Header, payload: Vec<u8>, signature: Vec<u8>) -> Self {
+        Self {
+            header,
+            payload,
+            signature,
+        }
+    }
+
+    /// Returns the `header.payload` bytes that an external signer operates on.
+    pub fn signing_input(&self) -> Result<Vec<u8>, Error> {
+        let header = encode_segment(&self.header)?;
+        let payload = general_purpose::URL_SAFE_NO_PAD.encode(&self.payload);
+        Ok(format!("{}.{}", header, payload).into_bytes())
+    }
+
+    /// Parses a `CompactJws` from its `header.payload.signature` textual form.
+    pub fn parse(value: &str) -> Result<Self, Error> {
+        let mut parts = value.split('.');
+        let header = parts.next().ok_or(Error::InvalidJws)?;
+        let payload = parts.next().ok_or(Error::InvalidJws)?;
+        let signature = parts.next().ok_or(Error::InvalidJws)?;
+        if parts.next().is_some() {
+            return Err(Error::InvalidJws);
+        }
+
+        let header_bytes = decode_segment(header)?;
+        let header: Header =
+            serde_json::from_slice(&header_bytes).map_err(|_| Error::InvalidJws)?;
+
+        Ok(Self {
+            header,
+            payload: decode_segment(payload)?,
+            signature: decode_segment(signature)?,
+        })
+    }
+
+    /// Renders the `CompactJws` as its `header.payload.signature` textual form.
+    pub fn to_compact(&self) -> Result<String, Error> {
+        let header = encode_segment(&self.header)?;
+        let payload = general_purpose::URL_SAFE_NO_PAD.encode(&self.payload);
+        let signature = general_purpose::URL_SAFE_NO_PAD.encode(&self.signature);
+        Ok(format!("{}.{}.{}", header, payload, signature))
+    }
+}
+
+/// A JWS in the flattened JSON serialization, as defined by
+/// [RFC 7515 §7.2.2](https://tools.ietf.org/html/rfc7515#section-7.2.2).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FlattenedJwsJson {
+    /// The base64url-encoded protected header.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protected: Option<String>,
+    /// The unprotected header.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub header: Option<Header>,
+    /// The base64url-encoded payload.
+    pub payload: String,
+    /// The base64url-encoded signature.
+    pub signature: String,
+}
+
+impl FlattenedJwsJson {
+    /// Returns the decoded protected header, if present.
+    pub fn protected_header(&self) -> Result<Option<Header>, Error> {
+        match &self.protected {
+            Some(protected) => {
+                let bytes = decode_segment(protected)?;
+                let header = serde_json::from_slice(&bytes).map_err(|_| Error::InvalidJws)?;
+                Ok(Some(header))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Returns the decoded payload bytes.
+    pub fn payload_bytes(&self) -> Result<Vec<u8>, Error> {
+        decode_segment(&self.payload)
+    }
+
+    /// Returns the decoded signature bytes.
+    pub fn signature_bytes(&self) -> Result<Vec<u8>, Error> {
+        decode_segment(&self.signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_compact_jws_round_trip() {
+        let mut header = Header::new();
+        header.alg = Some("RS256".to_string());
+
+        let jws = CompactJws::new(header, b"payload".to_vec(), b"signature".to_vec());
+        let compact = jws.to_compact().unwrap();
+        let parsed = CompactJws::parse(&compact).unwrap();
+
+        assert_eq!(parsed, jws);
+        assert_eq!(parsed.payload, b"payload".to_vec());
+    }
+
+    #[test]
+    fn test_compact_jws_signing_input() {
+        let mut header = Header::new();
+        header.alg = Some("RS256".to_string());
+
+        let jws = CompactJws::new(header, b"payload".to_vec(), vec![]);
+        let signing_input = jws.signing_input().unwrap();
+        let compact = jws.to_compact().unwrap();
+        let prefix = compact.rsplit_once('.').unwrap().0;
+
+        assert_eq!(signing_input, prefix.as_bytes());
+    }
+
+    #[test]
+    fn test_compact_jws_rejects_wrong_segment_count() {
+        assert!(CompactJws::parse("only.two").is_err());
+        assert!(CompactJws::parse("too.many.segments.here").is_err());
+    }
+
+    #[test]
+    fn test_flattened_jws_json() {
+        let mut header = Header::new();
+        header.alg = Some("RS256".to_string());
+        let protected = general_purpose::URL_SAFE_NO_PAD
+            .encode(serde_json::to_vec(&header).unwrap());
+
+        let flattened = FlattenedJwsJson {
+            protected: Some(protected),
+            header: None,
+            payload: general_purpose::URL_SAFE_NO_PAD.encode(b"payload"),
+            signature: general_purpose::URL_SAFE_NO_PAD.encode(b"signature"),
+        };
+
+        assert_eq!(flattened.protected_header().unwrap(), Some(header));
+        assert_eq!(flattened.payload_bytes().unwrap(), b"payload".to_vec());
+        assert_eq!(flattened.signature_bytes().unwrap(), b"signature".to_vec());
+    }
+}
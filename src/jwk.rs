@@ -0,0 +1,249 @@
+// Copyright 2023 Antonio Estevez <aestevez@opencanarias.es>
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+
+// http://www.apache.org/licenses/LICENSE-2.0
+
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express
+// or implied. See the License for the specific language governing
+// permissions and limitations under the License.
+
+//! # JWK (JSON Web Key)
+//!
+//! Types to represent JSON Web Keys as defined by
+//! [RFC 7517](https://tools.ietf.org/html/rfc7517) and the key parameter
+//! registry of [RFC 7518](https://tools.ietf.org/html/rfc7518). The big-integer and
+//! byte-string key material is represented with [`Base64urlUInt`], so downstream code
+//! can parse and emit keys without hand-rolling base64url handling.
+//!
+//! Decoding a [`Base64urlUInt`] rejects any value longer than one octet whose first
+//! octet is zero. This applies to the byte-string fields (`x`, `y`, `d` and `k`) as
+//! well, so a key whose value in one of those fields begins with `0x00` does not parse.
+//!
+
+#![deny(missing_docs)]
+
+use crate::json::{OneOrMany, StringOrUri};
+use crate::misc::Base64urlUInt;
+
+use serde::{Deserialize, Serialize};
+
+/// RSA key parameters, as defined by
+/// [RFC 7518 §6.3](https://tools.ietf.org/html/rfc7518#section-6.3).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RsaParams {
+    /// The modulus value.
+    pub n: Base64urlUInt,
+    /// The exponent value.
+    pub e: Base64urlUInt,
+    /// The private exponent value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub d: Option<Base64urlUInt>,
+    /// The first prime factor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub p: Option<Base64urlUInt>,
+    /// The second prime factor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub q: Option<Base64urlUInt>,
+    /// The first factor CRT exponent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dp: Option<Base64urlUInt>,
+    /// The second factor CRT exponent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dq: Option<Base64urlUInt>,
+    /// The first CRT coefficient.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub qi: Option<Base64urlUInt>,
+}
+
+impl RsaParams {
+    /// Creates a new public `RsaParams` from the modulus and exponent.
+    pub fn new(n: Base64urlUInt, e: Base64urlUInt) -> Self {
+        Self {
+            n,
+            e,
+            d: None,
+            p: None,
+            q: None,
+            dp: None,
+            dq: None,
+            qi: None,
+        }
+    }
+}
+
+/// Elliptic curve key parameters, as defined by
+/// [RFC 7518 §6.2](https://tools.ietf.org/html/rfc7518#section-6.2).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EcParams {
+    /// The curve identifier, e.g. `"P-256"`.
+    pub crv: String,
+    /// The x coordinate.
+    pub x: Base64urlUInt,
+    /// The y coordinate.
+    pub y: Base64urlUInt,
+    /// The private key value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub d: Option<Base64urlUInt>,
+}
+
+impl EcParams {
+    /// Creates a new public `EcParams` from the curve identifier and coordinates.
+    pub fn new(crv: impl Into<String>, x: Base64urlUInt, y: Base64urlUInt) -> Self {
+        Self {
+            crv: crv.into(),
+            x,
+            y,
+            d: None,
+        }
+    }
+}
+
+/// Symmetric (octet sequence) key parameters, as defined by
+/// [RFC 7518 §6.4](https://tools.ietf.org/html/rfc7518#section-6.4).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OctParams {
+    /// The symmetric key value.
+    pub k: Base64urlUInt,
+}
+
+impl OctParams {
+    /// Creates a new `OctParams` from the key value.
+    pub fn new(k: Base64urlUInt) -> Self {
+        Self { k }
+    }
+}
+
+/// Key-type-specific parameters, tagged by the `kty` member as defined by
+/// [RFC 7518 §6.1](https://tools.ietf.org/html/rfc7518#section-6.1).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kty")]
+pub enum JwkParams {
+    /// An RSA key, tagged `"RSA"`.
+    #[serde(rename = "RSA")]
+    Rsa(RsaParams),
+    /// An elliptic curve key, tagged `"EC"`.
+    #[serde(rename = "EC")]
+    Ec(EcParams),
+    /// A symmetric key, tagged `"oct"`.
+    #[serde(rename = "oct")]
+    Oct(OctParams),
+}
+
+/// A JSON Web Key, as defined by [RFC 7517](https://tools.ietf.org/html/rfc7517).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Jwk {
+    /// The key-type-specific parameters.
+    #[serde(flatten)]
+    pub params: JwkParams,
+    /// The intended use of the key, e.g. `"sig"` or `"enc"`.
+    #[serde(rename = "use", skip_serializing_if = "Option::is_none")]
+    pub use_: Option<String>,
+    /// The operations the key is intended to be used for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_ops: Option<OneOrMany<String>>,
+    /// The algorithm intended for use with the key.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alg: Option<String>,
+    /// The key identifier.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub kid: Option<StringOrUri>,
+    /// A URI referring to a resource for an X.509 public key certificate or certificate chain.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub x5u: Option<StringOrUri>,
+}
+
+impl Jwk {
+    /// Creates a new `Jwk` from its key-type-specific parameters.
+    pub fn new(params: JwkParams) -> Self {
+        Self {
+            params,
+            use_: None,
+            key_ops: None,
+            alg: None,
+            kid: None,
+            x5u: None,
+        }
+    }
+
+    /// Returns the key type of the `Jwk`.
+    pub fn kty(&self) -> &'static str {
+        match &self.params {
+            JwkParams::Rsa(_) => "RSA",
+            JwkParams::Ec(_) => "EC",
+            JwkParams::Oct(_) => "oct",
+        }
+    }
+
+    /// Returns the RSA parameters, if this is an RSA key.
+    pub fn as_rsa(&self) -> Option<&RsaParams> {
+        match &self.params {
+            JwkParams::Rsa(params) => Some(params),
+            _ => None,
+        }
+    }
+
+    /// Returns the elliptic curve parameters, if this is an EC key.
+    pub fn as_ec(&self) -> Option<&EcParams> {
+        match &self.params {
+            JwkParams::Ec(params) => Some(params),
+            _ => None,
+        }
+    }
+
+    /// Returns the symmetric key parameters, if this is an oct key.
+    pub fn as_oct(&self) -> Option<&OctParams> {
+        match &self.params {
+            JwkParams::Oct(params) => Some(params),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn test_jwk_rsa_round_trip() {
+        let params = RsaParams::new(
+            Base64urlUInt(vec![1, 2, 3]),
+            Base64urlUInt(vec![1, 0, 1]),
+        );
+        let jwk = Jwk::new(JwkParams::Rsa(params));
+        assert_eq!(jwk.kty(), "RSA");
+
+        let json = serde_json::to_string(&jwk).unwrap();
+        let parsed: Jwk = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, jwk);
+        assert!(parsed.as_rsa().is_some());
+    }
+
+    #[test]
+    fn test_jwk_ec_round_trip() {
+        let params = EcParams::new("P-256", Base64urlUInt(vec![1]), Base64urlUInt(vec![2]));
+        let jwk = Jwk::new(JwkParams::Ec(params));
+        assert_eq!(jwk.kty(), "EC");
+
+        let json = serde_json::to_string(&jwk).unwrap();
+        let parsed: Jwk = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, jwk);
+        assert!(parsed.as_ec().is_some());
+    }
+
+    #[test]
+    fn test_jwk_oct_round_trip() {
+        let jwk = Jwk::new(JwkParams::Oct(OctParams::new(Base64urlUInt(vec![9, 9]))));
+        assert_eq!(jwk.kty(), "oct");
+
+        let json = serde_json::to_string(&jwk).unwrap();
+        let parsed: Jwk = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, jwk);
+        assert!(parsed.as_oct().is_some());
+    }
+}
@@ -52,6 +52,12 @@ pub mod misc;
 #[cfg(feature = "json")]
 pub mod json;
 
+#[cfg(feature = "jwk")]
+pub mod jwk;
+
+#[cfg(feature = "jose")]
+pub mod jose;
+
 
 pub use error::Error;
 
@@ -67,7 +73,28 @@ pub use json::StringOrUri;
 #[cfg(feature = "json")]
 pub use json::ObjectWithId;
 
+#[cfg(feature = "json")]
+pub use json::Context;
+
+#[cfg(feature = "json")]
+pub use json::ContextEntry;
+
 pub use misc::Base64urlUInt;
 
+#[cfg(feature = "jwk")]
+pub use jwk::Jwk;
+
+#[cfg(feature = "jwk")]
+pub use jwk::JwkParams;
+
+#[cfg(feature = "jose")]
+pub use jose::Header;
+
+#[cfg(feature = "jose")]
+pub use jose::CompactJws;
+
+#[cfg(feature = "jose")]
+pub use jose::FlattenedJwsJson;
+
 #[cfg(test)]
 mod tests {}
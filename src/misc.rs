@@ -15,11 +15,13 @@
 //! # Miscellany of types
 //!
 
-use num_bigint::BigInt;
+use num_bigint::{BigInt, Sign};
 use base64::{engine::general_purpose, Engine as _};
 use serde::{Deserialize, Serialize};
 use zeroize::Zeroize;
 
+use crate::Error;
+
 use std::convert::TryFrom;
 use std::fmt::{self, Display, Formatter};
 
@@ -33,11 +35,52 @@ pub struct Base64urlUInt(pub Vec<u8>);
 type Base64urlUIntString = String;
 
 impl TryFrom<String> for Base64urlUInt {
-    type Error = base64::DecodeError;
+    type Error = Error;
     fn try_from(data: String) -> Result<Self, Self::Error> {
-        Ok(Base64urlUInt(
-            general_purpose::URL_SAFE_NO_PAD.decode(data)?,
-        ))
+        let bytes = general_purpose::URL_SAFE_NO_PAD
+            .decode(data)
+            .map_err(|_| Error::InvalidBase64urlUInt)?;
+
+        // RFC 7518 "base64url-uint" is canonical: no superfluous leading zero octets,
+        // except for the single `0x00` byte that represents the value 0.
+        if bytes.len() > 1 && bytes[0] == 0 {
+            return Err(Error::InvalidBase64urlUInt);
+        }
+
+        Ok(Base64urlUInt(bytes))
+    }
+}
+
+impl TryFrom<BigInt> for Base64urlUInt {
+    type Error = Error;
+    fn try_from(value: BigInt) -> Result<Self, Self::Error> {
+        if value.sign() == Sign::Minus {
+            return Err(Error::InvalidBase64urlUInt);
+        }
+
+        let (_, bytes) = value.to_bytes_be();
+        if bytes.is_empty() {
+            // RFC 7518 encodes the value 0 as a single `0x00` byte.
+            Ok(Base64urlUInt(vec![0]))
+        } else {
+            Ok(Base64urlUInt(bytes))
+        }
+    }
+}
+
+impl From<u64> for Base64urlUInt {
+    fn from(value: u64) -> Self {
+        BigInt::from(value)
+            .try_into()
+            .expect("a non-negative u64 always converts")
+    }
+}
+
+impl From<usize> for Base64urlUInt {
+    fn from(value: usize) -> Self {
+        BigInt::from(value)
+            .try_into()
+            .expect("a non-negative usize always converts")
     }
 }
 
@@ -83,4 +126,36 @@ mod tests {
         assert_eq!(bigint, BigInt::from(66051));
         assert_eq!(data.to_string(), "AQID");
     }
+
+    #[test]
+    fn test_base64url_uint_rejects_non_canonical_leading_zero() {
+        // "AAECAw" decodes to [0, 1, 2, 3], a non-minimal big-endian encoding.
+        assert!(Base64urlUInt::try_from("AAECAw".to_string()).is_err());
+    }
+
+    #[test]
+    fn test_bigint_to_base64url_uint_round_trip() {
+        let data = Base64urlUInt::try_from(BigInt::from(66051)).unwrap();
+        assert_eq!(data, Base64urlUInt(vec![1, 2, 3]));
+
+        let bigint: BigInt = data.into();
+        assert_eq!(bigint, BigInt::from(66051));
+    }
+
+    #[test]
+    fn test_bigint_zero_encodes_as_single_zero_byte() {
+        let data = Base64urlUInt::try_from(BigInt::from(0)).unwrap();
+        assert_eq!(data, Base64urlUInt(vec![0]));
+    }
+
+    #[test]
+    fn test_bigint_negative_is_rejected() {
+        assert!(Base64urlUInt::try_from(BigInt::from(-1)).is_err());
+    }
+
+    #[test]
+    fn test_u64_and_usize_convenience() {
+        assert_eq!(Base64urlUInt::from(66051u64), Base64urlUInt(vec![1, 2, 3]));
+        assert_eq!(Base64urlUInt::from(66051usize), Base64urlUInt(vec![1, 2, 3]));
+    }
 }
\ No newline at end of file